@@ -1,11 +1,14 @@
 use arrow::offset::{Offset, Offsets};
 use arrow::pushable::Pushable;
+use polars_error::{polars_err, PolarsError, PolarsResult};
 
 /// [`Pushable`] for variable length binary data.
 #[derive(Debug, Default)]
 pub struct Binary<O: Offset> {
     pub offsets: Offsets<O>,
     pub values: Vec<u8>,
+    /// The first UTF-8 validation error encountered by [`Binary::push_utf8`], if any.
+    utf8_error: Option<PolarsError>,
 }
 
 impl<O: Offset> Binary<O> {
@@ -14,21 +17,46 @@ impl<O: Offset> Binary<O> {
         Self {
             offsets: Offsets::with_capacity(capacity),
             values: Vec::with_capacity(capacity.min(100) * 24),
+            utf8_error: None,
         }
     }
 
+    /// Like [`Binary::with_capacity`], but seeds the `values` allocation from `total_bytes`, the
+    /// column chunk's total uncompressed byte size as found in page/column statistics, instead of
+    /// guessing from the row count alone.
     #[inline]
-    pub fn push(&mut self, v: &[u8]) {
-        if self.offsets.len_proxy() == 100 && self.offsets.capacity() > 100 {
-            let bytes_per_row = self.values.len() / 100 + 1;
-            let bytes_estimate = bytes_per_row * self.offsets.capacity();
-            if bytes_estimate > self.values.capacity() {
-                self.values.reserve(bytes_estimate - self.values.capacity());
-            }
+    pub fn with_capacity_and_bytes(rows: usize, total_bytes: usize) -> Self {
+        Self {
+            offsets: Offsets::with_capacity(rows),
+            values: Vec::with_capacity(total_bytes),
+            utf8_error: None,
         }
+    }
+
+    #[inline]
+    pub fn push(&mut self, v: &[u8]) {
+        self.try_push(v).unwrap()
+    }
 
+    /// Like [`Binary::push`], but propagates an offset overflow (e.g. a cumulative byte length
+    /// above `i32::MAX` when `O = i32`) as a [`PolarsError`](polars_error::PolarsError) instead of
+    /// panicking.
+    #[inline]
+    pub fn try_push(&mut self, v: &[u8]) -> PolarsResult<()> {
+        // Push the offset first: if it overflows, `values` must stay untouched so the two
+        // buffers never go out of sync (and the caller can recover via `into_i64`).
+        self.offsets
+            .try_push(v.len())
+            .map_err(|e| polars_err!(ComputeError: "failed to push binary value: {e}"))?;
         self.values.extend(v);
-        self.offsets.try_push(v.len()).unwrap()
+        Ok(())
+    }
+
+    /// Reserves `additional_bytes` in the `values` buffer up front, for callers that know the
+    /// total uncompressed size of the remaining data (see [`Binary::with_capacity_and_bytes`]).
+    #[inline]
+    pub fn reserve_bytes(&mut self, additional_bytes: usize) {
+        self.values.reserve(additional_bytes);
     }
 
     #[inline]
@@ -40,6 +68,85 @@ impl<O: Offset> Binary<O> {
     pub fn len(&self) -> usize {
         self.offsets.len_proxy()
     }
+
+    /// Rebuilds a [`Binary`] from buffers recycled from a previously decoded column chunk, so a
+    /// multi-chunk read can reuse the same `values`/`offsets` allocations from one chunk to the
+    /// next instead of reallocating. `values` and `offsets` must already be cleared; their
+    /// capacity is preserved and [`Pushable::reserve`]'s preallocation still applies on top of
+    /// it.
+    ///
+    /// Pair with [`Binary::into_buffers`], which hands the buffers back after a chunk is done.
+    #[inline]
+    pub fn from_recycled(values: Vec<u8>, offsets: Offsets<O>) -> Self {
+        debug_assert!(values.is_empty());
+        debug_assert_eq!(offsets.len_proxy(), 0);
+        Self {
+            offsets,
+            values,
+            utf8_error: None,
+        }
+    }
+
+    /// Tears this [`Binary`] back down into its `values` and `offsets` storage, truncating both
+    /// (not zeroing) while preserving their capacity, so they can be handed to
+    /// [`Binary::from_recycled`] for the next column chunk instead of reallocating.
+    #[inline]
+    pub fn into_buffers(mut self) -> (Vec<u8>, Offsets<O>) {
+        self.values.clear();
+        self.offsets.clear();
+        (self.values, self.offsets)
+    }
+
+    /// Like [`Binary::push`], but validates `v` as UTF-8 while it's appended instead of leaving
+    /// that to a later full-buffer `std::str::from_utf8` scan over `values`.
+    ///
+    /// The first validation failure is recorded and returned by [`Binary::try_freeze_as_utf8`];
+    /// it doesn't interrupt the push loop, since the caller usually wants to keep decoding the
+    /// rest of the page before reporting the error. Keep using [`Binary::push`] for genuinely
+    /// binary columns.
+    #[inline]
+    pub fn push_utf8(&mut self, v: &[u8]) {
+        if self.utf8_error.is_none() {
+            if let Err(e) = std::str::from_utf8(v) {
+                self.utf8_error = Some(polars_err!(ComputeError: "invalid utf-8 in binary value: {e}"));
+            }
+        }
+        self.push(v)
+    }
+
+    /// Returns `self` if every value pushed via [`Binary::push_utf8`] was valid UTF-8, otherwise
+    /// the first validation error encountered, without re-scanning `values`.
+    #[inline]
+    pub fn try_freeze_as_utf8(self) -> PolarsResult<Self> {
+        match self.utf8_error {
+            Some(e) => Err(e),
+            None => Ok(self),
+        }
+    }
+}
+
+impl Binary<i32> {
+    /// Promotes this `Binary<i32>` into a `Binary<i64>`, reusing the already-filled `values`
+    /// buffer and re-widening the offsets.
+    ///
+    /// Use this to recover from an offset overflow reported by [`Binary::try_push`] instead of
+    /// failing the whole column: convert once the cumulative byte length would exceed
+    /// `i32::MAX` and keep pushing into the wider type.
+    pub fn into_i64(self) -> Binary<i64> {
+        let mut offsets = Offsets::<i64>::with_capacity(self.offsets.len_proxy());
+        // `as_slice()` includes the leading zero sentinel that `Offsets` always keeps, so windows
+        // of 2 yield exactly `len_proxy()` lengths, one per value already pushed.
+        for window in self.offsets.as_slice().windows(2) {
+            let length = window[1].to_usize() - window[0].to_usize();
+            offsets.try_push(length).unwrap();
+        }
+
+        Binary {
+            offsets,
+            values: self.values,
+            utf8_error: self.utf8_error,
+        }
+    }
 }
 
 impl<'a, O: Offset> Pushable<&'a [u8]> for Binary<O> {
@@ -84,6 +191,10 @@ impl<'a, O: Offset> Pushable<&'a [u8]> for Binary<O> {
 pub struct BinaryIter<'a> {
     values: &'a [u8],
 
+    /// The total number of bytes this [`BinaryIter`] was created with, used to report the byte
+    /// offset of a value in [`BinaryIter::try_next`] errors.
+    total_len: usize,
+
     /// A maximum number of items that this [`BinaryIter`] may produce.
     ///
     /// This equal the length of the iterator i.f.f. the data encoded by the [`BinaryIter`] is not
@@ -95,6 +206,7 @@ impl<'a> BinaryIter<'a> {
     pub fn new(values: &'a [u8], max_num_values: usize) -> Self {
         Self {
             values,
+            total_len: values.len(),
             max_num_values,
         }
     }
@@ -103,6 +215,89 @@ impl<'a> BinaryIter<'a> {
     pub fn len_when_not_nullable(&self) -> usize {
         self.max_num_values
     }
+
+    /// The byte offset of the next value, relative to the buffer this iterator was created with.
+    #[inline]
+    fn offset(&self) -> usize {
+        self.total_len - self.values.len()
+    }
+
+    /// Like [`Iterator::next`], but verifies the length prefix and the encoded length against the
+    /// remaining buffer instead of panicking or reading out of bounds.
+    ///
+    /// Use this over the plain iterator when decoding a column chunk that hasn't already been
+    /// validated, so a truncated or corrupt PLAIN page surfaces a [`PolarsError`](polars_error::PolarsError)
+    /// instead of aborting the process.
+    #[inline]
+    pub fn try_next(&mut self) -> Option<PolarsResult<&'a [u8]>> {
+        if self.max_num_values == 0 {
+            return if self.values.is_empty() {
+                None
+            } else {
+                Some(Err(polars_err!(
+                    ComputeError:
+                    "binary page at offset {}: {} trailing byte(s) after the last declared value",
+                    self.offset(), self.values.len()
+                )))
+            };
+        }
+
+        if self.values.len() < 4 {
+            return Some(Err(polars_err!(
+                ComputeError:
+                "binary page at offset {}: expected a 4-byte length prefix, found {} byte(s)",
+                self.offset(), self.values.len()
+            )));
+        }
+
+        let (length, remaining) = self.values.split_at(4);
+        let length: [u8; 4] = length.try_into().unwrap();
+        let length = u32::from_le_bytes(length) as usize;
+
+        if length > remaining.len() {
+            return Some(Err(polars_err!(
+                ComputeError:
+                "binary page at offset {}: value declares length {} but only {} byte(s) remain",
+                self.offset(), length, remaining.len()
+            )));
+        }
+
+        let (result, remaining) = remaining.split_at(length);
+        self.max_num_values -= 1;
+        self.values = remaining;
+        Some(Ok(result))
+    }
+
+    /// Advances the iterator by `n` values without materializing them, for callers that only
+    /// need to fast-forward past rows excluded by row-group/page skipping or a pushed-down
+    /// filter mask.
+    ///
+    /// This is O(`n`) because the length-prefixed layout isn't random-access: each skipped value
+    /// still requires reading its 4-byte length prefix to find the next one.
+    #[inline]
+    pub fn skip_values(&mut self, n: usize) {
+        for _ in 0..n {
+            if self.next().is_none() {
+                break;
+            }
+        }
+    }
+
+    /// Like [`BinaryIter::skip_values`], but verifies each length prefix and encoded length
+    /// against the remaining buffer, returning a recoverable error instead of panicking if `n`
+    /// would skip past the end of a truncated or corrupt page.
+    #[inline]
+    pub fn try_skip_values(&mut self, n: usize) -> PolarsResult<()> {
+        for _ in 0..n {
+            match self.try_next() {
+                Some(result) => {
+                    result?;
+                },
+                None => break,
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<'a> Iterator for BinaryIter<'a> {
@@ -128,4 +323,10 @@ impl<'a> Iterator for BinaryIter<'a> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (0, Some(self.max_num_values))
     }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.skip_values(n);
+        self.next()
+    }
 }